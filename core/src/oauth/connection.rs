@@ -0,0 +1,99 @@
+use crate::oauth::credential::Credential;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionProvider {
+    Notion,
+}
+
+pub struct Connection {}
+
+pub struct FinalizeResult {
+    pub redirect_uri: String,
+    pub code: String,
+    pub access_token: String,
+    pub access_token_expiry: Option<u64>,
+    pub refresh_token: Option<String>,
+    pub raw_json: serde_json::Value,
+}
+
+pub struct RefreshResult {
+    pub access_token: String,
+    pub access_token_expiry: Option<u64>,
+    pub raw_json: serde_json::Value,
+}
+
+pub struct RevokeResult {}
+
+#[derive(Debug)]
+pub enum ProviderError {
+    ActionNotSupportedError(String),
+    InternalError(anyhow::Error),
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProviderError::ActionNotSupportedError(msg) => {
+                write!(f, "Action not supported: {}", msg)
+            }
+            ProviderError::InternalError(err) => write!(f, "Internal error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+impl From<anyhow::Error> for ProviderError {
+    fn from(err: anyhow::Error) -> Self {
+        ProviderError::InternalError(err)
+    }
+}
+
+#[async_trait]
+pub trait Provider {
+    fn id(&self) -> ConnectionProvider;
+
+    async fn finalize(
+        &self,
+        connection: &Connection,
+        related_credentials: Option<Credential>,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<FinalizeResult, ProviderError>;
+
+    async fn refresh(
+        &self,
+        connection: &Connection,
+        related_credentials: Option<Credential>,
+    ) -> Result<RefreshResult, ProviderError>;
+
+    // Actively invalidates the upstream grant. Providers that don't support remote
+    // revocation (or whose tokens can't be revoked via an API call) can rely on this
+    // default, which just reports the action as unsupported.
+    async fn revoke(
+        &self,
+        _connection: &Connection,
+        _related_credentials: Option<Credential>,
+    ) -> Result<RevokeResult, ProviderError> {
+        Err(ProviderError::ActionNotSupportedError(
+            "This provider does not support revoking connections".to_string(),
+        ))
+    }
+
+    fn scrubbed_raw_json(&self, raw_json: &serde_json::Value) -> Result<serde_json::Value> {
+        Ok(raw_json.clone())
+    }
+
+    fn reqwest_client(&self) -> reqwest::Client {
+        reqwest::Client::new()
+    }
+
+    fn handle_provider_request_error(&self, err: anyhow::Error) -> ProviderError {
+        ProviderError::InternalError(err)
+    }
+}
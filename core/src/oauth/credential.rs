@@ -0,0 +1,18 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub struct Credential {
+    content: HashMap<String, String>,
+}
+
+impl Credential {
+    pub fn new(content: HashMap<String, String>) -> Self {
+        Credential { content }
+    }
+
+    // Returns the underlying secrets (e.g. `access_token`, `refresh_token`) stored for
+    // this credential. The content is held in memory as is; no decryption happens here.
+    pub fn unseal(&self) -> Result<&HashMap<String, String>> {
+        Ok(&self.content)
+    }
+}
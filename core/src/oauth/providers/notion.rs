@@ -1,6 +1,7 @@
 use crate::oauth::{
     connection::{
         Connection, ConnectionProvider, FinalizeResult, Provider, ProviderError, RefreshResult,
+        RevokeResult,
     },
     credential::Credential,
     providers::utils::execute_request,
@@ -88,6 +89,41 @@ impl Provider for NotionConnectionProvider {
         ))?
     }
 
+    async fn revoke(
+        &self,
+        _connection: &Connection,
+        related_credentials: Option<Credential>,
+    ) -> Result<RevokeResult, ProviderError> {
+        let credentials = related_credentials.ok_or_else(|| {
+            ProviderError::ActionNotSupportedError(
+                "Missing related credentials to revoke Notion token".to_string(),
+            )
+        })?;
+
+        let access_token = match credentials.unseal()?.get("access_token") {
+            Some(token) => token.clone(),
+            None => Err(anyhow!("Missing `access_token` in related credentials"))?,
+        };
+
+        let body = json!({
+            "token": access_token,
+        });
+
+        let req = self
+            .reqwest_client()
+            .post("https://api.notion.com/v1/oauth/revoke")
+            .header("Accept", "application/json")
+            .header("Content-Type", "application/json")
+            .header("Authorization", format!("Basic {}", self.basic_auth()))
+            .json(&body);
+
+        execute_request(ConnectionProvider::Notion, req)
+            .await
+            .map_err(|e| self.handle_provider_request_error(e))?;
+
+        Ok(RevokeResult {})
+    }
+
     fn scrubbed_raw_json(&self, raw_json: &serde_json::Value) -> Result<serde_json::Value> {
         let raw_json = match raw_json.clone() {
             serde_json::Value::Object(mut map) => {